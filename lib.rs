@@ -12,6 +12,48 @@ mod erc20 {
         total_supply: Balance,
         balances: Mapping<AccountId, Balance>,
         allowances: Mapping<(AccountId, AccountId), Balance>,
+        bridge_authority: AccountId,
+        consumed_receipts: Mapping<[u8; 32], ()>,
+        viewing_keys: Mapping<AccountId, [u8; 32]>,
+        admin: AccountId,
+        status: ContractStatus,
+        decimals: u8,
+        minter: AccountId,
+        tx_history: Mapping<(AccountId, u32), Tx>,
+        tx_count: Mapping<AccountId, u32>,
+    }
+
+    /// A single entry in an account's transaction history, modeled on SNIP20's `RichTx`.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Tx {
+        pub action: TxAction,
+        pub counterparty: Option<AccountId>,
+        pub amount: Balance,
+        pub block_number: BlockNumber,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum TxAction {
+        Transfer,
+        Mint,
+        Burn,
+    }
+
+    /// Tiered operator killswitch, modeled on SNIP20's `ContractStatus`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ContractStatus {
+        /// Every message behaves normally.
+        Normal,
+        /// Transfers, approvals, allowance changes, and minting/burning are
+        /// rejected. Balance and allowance queries still work.
+        StopTransactions,
+        /// Everything `StopTransactions` rejects, plus the viewing-key queries.
+        /// `balance_of`/`allowance` are unauthenticated reads of already-public
+        /// chain state and are never gated by any tier.
+        StopAll,
     }
 
     #[ink(event)]
@@ -37,6 +79,12 @@ mod erc20 {
     pub enum Error {
         InsufficientBalance,
         InsufficientAllowance,
+        ReceiptAlreadyUsed,
+        InvalidSignature,
+        WrongViewingKey,
+        Unauthorized,
+        ContractPaused,
+        Overflow,
     }
 
     type Result<T> = core::result::Result<T, Error>;
@@ -44,7 +92,14 @@ mod erc20 {
     impl Erc20 {
         /// Constructor that initializes the `bool` value to the given `init_value`.
         #[ink(constructor)]
-        pub fn new(name: String, symbol: String, total_supply: Balance) -> Self {
+        pub fn new(
+            name: String,
+            symbol: String,
+            decimals: u8,
+            total_supply: Balance,
+            bridge_authority: AccountId,
+            minter: AccountId,
+        ) -> Self {
             let mut balances = Mapping::new();
             balances.insert(Self::env().caller(), &total_supply);
 
@@ -56,6 +111,15 @@ mod erc20 {
                 total_supply,
                 balances,
                 allowances: Default::default(),
+                bridge_authority,
+                consumed_receipts: Mapping::new(),
+                viewing_keys: Mapping::new(),
+                admin: Self::env().caller(),
+                status: ContractStatus::Normal,
+                decimals,
+                minter,
+                tx_history: Mapping::new(),
+                tx_count: Mapping::new(),
             }
         }
 
@@ -79,6 +143,11 @@ mod erc20 {
             self.symbol.clone()
         }
 
+        #[ink(message)]
+        pub fn decimals(&self) -> u8 {
+            self.decimals
+        }
+
         #[ink(message)]
         pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
             let from = self.env().caller();
@@ -87,17 +156,18 @@ mod erc20 {
 
         #[ink(message)]
         pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
             let spender = self.env().caller();
             let allowance = self.allowances.get(&(from, spender)).unwrap_or_default();
-            if allowance < value {
-                return Err(Error::InsufficientAllowance);
-            }
-            self.allowances.insert((from, spender), &(allowance - value));
-            self._transfer(&from, &to, value)
+            let new_allowance = allowance.checked_sub(value).ok_or(Error::InsufficientAllowance)?;
+            self._transfer(&from, &to, value)?;
+            self.allowances.insert((from, spender), &new_allowance);
+            Ok(())
         }
 
         #[ink(message)]
         pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
             let owner = self.env().caller();
             self.allowances.insert((owner, spender), &value);
 
@@ -105,50 +175,177 @@ mod erc20 {
             Ok(())
         }
 
+        /// Restricted to `admin`.
+        #[ink(message)]
+        pub fn set_contract_status(&mut self, status: ContractStatus) -> Result<()> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.status = status;
+            Ok(())
+        }
+
+        fn ensure_transfers_allowed(&self) -> Result<()> {
+            match self.status {
+                ContractStatus::Normal => Ok(()),
+                ContractStatus::StopTransactions | ContractStatus::StopAll => Err(Error::ContractPaused),
+            }
+        }
+
+        fn ensure_queries_allowed(&self) -> Result<()> {
+            match self.status {
+                ContractStatus::Normal | ContractStatus::StopTransactions => Ok(()),
+                ContractStatus::StopAll => Err(Error::ContractPaused),
+            }
+        }
+
         #[ink(message)]
         pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
             self.allowances.get(&(owner, spender)).unwrap_or_default()
         }
 
+        /// Sets the caller's viewing key for `balance_of_with_key`/`allowance_with_key`.
+        ///
+        /// NOTE: unlike SNIP20's Secret Network origin, this chain has no
+        /// confidential-compute layer, so `key` (and the `entropy` passed to
+        /// `create_viewing_key`) is visible in the mempool and the finalized
+        /// block. This gates casual `balance_of`-style reads, not a determined
+        /// chain observer.
+        #[ink(message)]
+        pub fn set_viewing_key(&mut self, key: String) {
+            let caller = self.env().caller();
+            let hashed = Self::hash_viewing_key(&key);
+            self.viewing_keys.insert(caller, &hashed);
+        }
+
+        /// Derives a viewing key from the caller, `entropy`, and the current block.
+        #[ink(message)]
+        pub fn create_viewing_key(&mut self, entropy: String) -> String {
+            let caller = self.env().caller();
+
+            let mut input = ink::prelude::vec::Vec::new();
+            input.extend_from_slice(caller.as_ref());
+            input.extend_from_slice(entropy.as_bytes());
+            input.extend_from_slice(&self.env().block_number().to_be_bytes());
+            input.extend_from_slice(&self.env().block_timestamp().to_be_bytes());
+
+            let mut seed = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&input, &mut seed);
+
+            let key = Self::encode_hex(&seed);
+            self.viewing_keys.insert(caller, &Self::hash_viewing_key(&key));
+            key
+        }
+
+        #[ink(message)]
+        pub fn balance_of_with_key(&self, owner: AccountId, key: String) -> Result<Balance> {
+            self.ensure_queries_allowed()?;
+            self.check_viewing_key(&owner, &key)?;
+            Ok(self.balance_of(owner))
+        }
+
+        #[ink(message)]
+        pub fn allowance_with_key(&self, owner: AccountId, spender: AccountId, key: String) -> Result<Balance> {
+            self.ensure_queries_allowed()?;
+            self.check_viewing_key(&owner, &key)?;
+            Ok(self.allowance(owner, spender))
+        }
+
+        fn check_viewing_key(&self, owner: &AccountId, key: &str) -> Result<()> {
+            let stored = self.viewing_keys.get(owner).ok_or(Error::WrongViewingKey)?;
+            if stored != Self::hash_viewing_key(key) {
+                return Err(Error::WrongViewingKey);
+            }
+            Ok(())
+        }
+
+        fn hash_viewing_key(key: &str) -> [u8; 32] {
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(key.as_bytes(), &mut hash);
+            hash
+        }
+
+        fn encode_hex(bytes: &[u8; 32]) -> String {
+            const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+            let mut out = ink::prelude::vec::Vec::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                out.push(HEX_CHARS[(byte >> 4) as usize]);
+                out.push(HEX_CHARS[(byte & 0x0f) as usize]);
+            }
+            String::from_utf8(out).expect("hex encoding is always valid utf8")
+        }
+
         #[ink(message)]
         pub fn increase_allowance(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
             let owner = self.env().caller();
             let allowance = self.allowances.get(&(owner, spender)).unwrap_or_default();
-            self.allowances.insert((owner, spender), &(allowance + value));
+            let new_allowance = allowance.checked_add(value).ok_or(Error::Overflow)?;
+            self.allowances.insert((owner, spender), &new_allowance);
             Ok(())
         }
 
         #[ink(message)]
         pub fn decrease_allowance(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
             let owner = self.env().caller();
             let allowance = self.allowances.get(&(owner, spender)).unwrap_or_default();
-            if allowance < value {
-                return Err(Error::InsufficientAllowance);
-            }
-            self.allowances.insert((owner, spender), &(allowance - value));
+            let new_allowance = allowance.checked_sub(value).ok_or(Error::InsufficientAllowance)?;
+            self.allowances.insert((owner, spender), &new_allowance);
             Ok(())
         }
 
         pub fn _transfer(&mut self, from: &AccountId, to: &AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
             let balance_from = self.balance_of(*from);
-            let balance_to = self.balance_of(*to);
+            let new_balance_from = balance_from.checked_sub(value).ok_or(Error::InsufficientBalance)?;
+            self.balances.insert(from, &new_balance_from);
 
-            if value > balance_from {
-                return Err(Error::InsufficientBalance);
-            }
+            // Re-read after the debit above so a self-transfer (from == to)
+            // credits the post-debit balance instead of double-counting `value`.
+            let balance_to = self.balance_of(*to);
+            let new_balance_to = balance_to.checked_add(value).ok_or(Error::Overflow)?;
+            self.balances.insert(to, &new_balance_to);
 
-            self.balances.insert(from, &(balance_from - value));
-            self.balances.insert(to, &(balance_to + value));
+            self.record_tx(from, TxAction::Transfer, Some(*to), value);
+            self.record_tx(to, TxAction::Transfer, Some(*from), value);
 
             self.env().emit_event(Transfer { from: Some(*from), to: Some(*to), value });
 
             Ok(())
         }
 
+        /// Mints `value` new tokens to `to`. Restricted to `minter`.
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
+            if self.env().caller() != self.minter {
+                return Err(Error::Unauthorized);
+            }
+            self._mint(&to, value)
+        }
+
+        /// Burns `value` tokens from `from`. Holders may burn their own balance;
+        /// burning on behalf of someone else is restricted to `minter`.
+        #[ink(message)]
+        pub fn burn(&mut self, from: AccountId, value: Balance) -> Result<()> {
+            self.ensure_transfers_allowed()?;
+            let caller = self.env().caller();
+            if caller != from && caller != self.minter {
+                return Err(Error::Unauthorized);
+            }
+            self._burn(&from, value)
+        }
+
         pub fn _mint(&mut self, to: &AccountId, value: Balance) -> Result<()> {
             let balance_to = self.balance_of(*to);
-            self.balances.insert(to, &(balance_to + value));
-            self.total_supply += value;
+            let new_balance_to = balance_to.checked_add(value).ok_or(Error::Overflow)?;
+            let new_total_supply = self.total_supply.checked_add(value).ok_or(Error::Overflow)?;
+
+            self.balances.insert(to, &new_balance_to);
+            self.total_supply = new_total_supply;
+
+            self.record_tx(to, TxAction::Mint, None, value);
 
             self.env().emit_event(Transfer { from: None, to: Some(*to), value });
 
@@ -157,17 +354,98 @@ mod erc20 {
 
         pub fn _burn(&mut self, from: &AccountId, value: Balance) -> Result<()> {
             let balance_from = self.balance_of(*from);
-            if value > balance_from {
-                return Err(Error::InsufficientBalance);
-            }
+            let new_balance_from = balance_from.checked_sub(value).ok_or(Error::InsufficientBalance)?;
+
+            self.balances.insert(from, &new_balance_from);
+            self.total_supply = self.total_supply.checked_sub(value).ok_or(Error::Overflow)?;
 
-            self.balances.insert(from, &(balance_from - value));
-            self.total_supply -= value;
+            self.record_tx(from, TxAction::Burn, None, value);
 
             self.env().emit_event(Transfer { from: Some(*from), to: None, value });
 
             Ok(())
         }
+
+        fn record_tx(&mut self, account: &AccountId, action: TxAction, counterparty: Option<AccountId>, amount: Balance) {
+            let index = self.tx_count.get(account).unwrap_or_default();
+            let tx = Tx {
+                action,
+                counterparty,
+                amount,
+                block_number: self.env().block_number(),
+            };
+            self.tx_history.insert((*account, index), &tx);
+            self.tx_count.insert(account, &(index + 1));
+        }
+
+        /// Returns a page of `account`'s transaction history, most recent first.
+        #[ink(message)]
+        pub fn transaction_history(&self, account: AccountId, page: u32, page_size: u32) -> ink::prelude::vec::Vec<Tx> {
+            let total = self.tx_count.get(account).unwrap_or_default();
+            let start = page.saturating_mul(page_size);
+
+            let mut txs = ink::prelude::vec::Vec::new();
+            for offset in 0..page_size {
+                let index = match start.checked_add(offset) {
+                    Some(index) if index < total => index,
+                    _ => break,
+                };
+                let reversed_index = total - 1 - index;
+                if let Some(tx) = self.tx_history.get((account, reversed_index)) {
+                    txs.push(tx);
+                }
+            }
+            txs
+        }
+
+        /// Mints `amount` to `recipient` if `signature` is an ECDSA signature over
+        /// `(this contract, recipient, amount, nonce)` recovering to
+        /// `bridge_authority`. Each message hash may only be consumed once.
+        #[ink(message)]
+        pub fn mint_with_receipt(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            nonce: u128,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            self.ensure_transfers_allowed()?;
+            let message_hash = Self::receipt_hash(&self.env().account_id(), &recipient, amount, nonce);
+
+            if self.consumed_receipts.contains(message_hash) {
+                return Err(Error::ReceiptAlreadyUsed);
+            }
+
+            let mut pub_key = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &message_hash, &mut pub_key)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            let mut signer = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&pub_key, &mut signer);
+            let signer = AccountId::from(signer);
+
+            if signer != self.bridge_authority {
+                return Err(Error::InvalidSignature);
+            }
+
+            self._mint(&recipient, amount)?;
+            self.consumed_receipts.insert(message_hash, &());
+
+            Ok(())
+        }
+
+        fn receipt_hash(contract: &AccountId, recipient: &AccountId, amount: Balance, nonce: u128) -> [u8; 32] {
+            let mut input = ink::prelude::vec::Vec::new();
+            input.extend_from_slice(contract.as_ref());
+            input.extend_from_slice(recipient.as_ref());
+            input.extend_from_slice(&amount.to_be_bytes());
+            input.extend_from_slice(&nonce.to_be_bytes());
+
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Keccak256>(&input, &mut hash);
+            hash
+        }
     }
 
     #[cfg(test)]
@@ -178,11 +456,14 @@ mod erc20 {
 
         #[ink::test]
         fn constructor_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let erc20 = Erc20::new(
                 String::from("Ink Test Token"),
                 String::from("ITT"),
-                1000);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
             assert_eq!(erc20.total_supply(), 1000);
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
 
@@ -201,11 +482,14 @@ mod erc20 {
 
         #[ink::test]
         pub fn transfer_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut erc20 = Erc20::new(
                 String::from("Ink Test Token"),
                 String::from("ITT"),
-                1000);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
             assert_eq!(erc20.balance_of(accounts.bob), 0);
 
@@ -220,11 +504,14 @@ mod erc20 {
 
         #[ink::test]
         fn invalid_transfer_should_fail() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut erc20 = Erc20::new(
                 String::from("Ink Test Token"),
                 String::from("ITT"),
-                1000);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
             ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
             let res = erc20.transfer(accounts.charlie, 20);
             assert!(res.is_err());
@@ -233,11 +520,14 @@ mod erc20 {
 
         #[ink::test]
         fn approve_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut erc20 = Erc20::new(
                 String::from("Ink Test Token"),
                 String::from("ITT"),
-                1000);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
             assert_eq!(erc20.allowance(accounts.alice, accounts.bob), 0);
             assert_eq!(erc20.approve(accounts.bob, 100), Ok(()));
             assert_eq!(erc20.allowance(accounts.alice, accounts.bob), 100);
@@ -245,11 +535,14 @@ mod erc20 {
 
         #[ink::test]
         fn transfer_from_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut erc20 = Erc20::new(
                 String::from("Ink Test Token"),
                 String::from("ITT"),
-                1000);
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
             assert_eq!(erc20.balance_of(accounts.alice), 1000);
             assert_eq!(erc20.balance_of(accounts.bob), 0);
             assert_eq!(erc20.allowance(accounts.alice, accounts.bob), 0);
@@ -270,6 +563,244 @@ mod erc20 {
             assert_eq!(erc20.balance_of(accounts.charlie), 100);
             assert_eq!(erc20.allowance(accounts.alice, accounts.bob), 0);
         }
+
+        #[ink::test]
+        fn mint_with_receipt_rejects_bad_signature() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+
+            let bogus_signature = [1u8; 65];
+            let res = erc20.mint_with_receipt(accounts.bob, 100, 0, bogus_signature);
+            assert_eq!(res, Err(Error::InvalidSignature));
+            assert_eq!(erc20.balance_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn mint_with_receipt_respects_contract_status() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+
+            assert_eq!(erc20.set_contract_status(ContractStatus::StopTransactions), Ok(()));
+            let bogus_signature = [1u8; 65];
+            assert_eq!(
+                erc20.mint_with_receipt(accounts.bob, 100, 0, bogus_signature),
+                Err(Error::ContractPaused)
+            );
+        }
+
+        #[ink::test]
+        fn viewing_key_gates_balance_and_allowance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+
+            assert_eq!(
+                erc20.balance_of_with_key(accounts.alice, String::from("secret")),
+                Err(Error::WrongViewingKey)
+            );
+
+            erc20.set_viewing_key(String::from("secret"));
+            assert_eq!(erc20.balance_of_with_key(accounts.alice, String::from("secret")), Ok(1000));
+            assert_eq!(
+                erc20.balance_of_with_key(accounts.alice, String::from("wrong")),
+                Err(Error::WrongViewingKey)
+            );
+
+            assert_eq!(erc20.approve(accounts.bob, 100), Ok(()));
+            assert_eq!(
+                erc20.allowance_with_key(accounts.alice, accounts.bob, String::from("secret")),
+                Ok(100)
+            );
+        }
+
+        #[ink::test]
+        fn create_viewing_key_is_usable_immediately() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+
+            let key = erc20.create_viewing_key(String::from("entropy"));
+            assert_eq!(erc20.balance_of_with_key(accounts.alice, key), Ok(1000));
+        }
+
+        #[ink::test]
+        fn set_contract_status_gates_transfers() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                erc20.set_contract_status(ContractStatus::StopTransactions),
+                Err(Error::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(erc20.set_contract_status(ContractStatus::StopTransactions), Ok(()));
+            assert_eq!(erc20.transfer(accounts.bob, 100), Err(Error::ContractPaused));
+            assert_eq!(erc20.balance_of(accounts.alice), 1000);
+
+            assert_eq!(erc20.set_contract_status(ContractStatus::Normal), Ok(()));
+            assert_eq!(erc20.transfer(accounts.bob, 100), Ok(()));
+        }
+
+        #[ink::test]
+        fn stop_all_additionally_blocks_viewing_key_queries() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.django);
+            erc20.set_viewing_key(String::from("secret"));
+
+            assert_eq!(erc20.set_contract_status(ContractStatus::StopTransactions), Ok(()));
+            assert_eq!(erc20.balance_of_with_key(accounts.alice, String::from("secret")), Ok(1000));
+
+            assert_eq!(erc20.set_contract_status(ContractStatus::StopAll), Ok(()));
+            assert_eq!(
+                erc20.balance_of_with_key(accounts.alice, String::from("secret")),
+                Err(Error::ContractPaused)
+            );
+        }
+
+        #[ink::test]
+        fn mint_and_burn_respect_minter_role() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.eve);
+            assert_eq!(erc20.decimals(), 18);
+
+            assert_eq!(erc20.mint(accounts.bob, 100), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(erc20.mint(accounts.bob, 100), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.bob), 100);
+            assert_eq!(erc20.total_supply(), 1100);
+
+            assert_eq!(erc20.burn(accounts.bob, 50), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.bob), 50);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(erc20.burn(accounts.alice, 50), Err(Error::Unauthorized));
+            assert_eq!(erc20.burn(accounts.bob, 50), Ok(()));
+            assert_eq!(erc20.balance_of(accounts.bob), 0);
+        }
+
+        #[ink::test]
+        fn mint_and_burn_respect_contract_status() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.eve);
+
+            assert_eq!(erc20.set_contract_status(ContractStatus::StopTransactions), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(erc20.mint(accounts.bob, 100), Err(Error::ContractPaused));
+            assert_eq!(erc20.burn(accounts.alice, 100), Err(Error::ContractPaused));
+        }
+
+        #[ink::test]
+        fn mint_rejects_total_supply_overflow() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                Balance::MAX,
+                accounts.django,
+                accounts.eve);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(erc20.mint(accounts.bob, 1), Err(Error::Overflow));
+        }
+
+        #[ink::test]
+        fn increase_allowance_rejects_overflow() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.eve);
+
+            assert_eq!(erc20.increase_allowance(accounts.bob, Balance::MAX), Ok(()));
+            assert_eq!(erc20.increase_allowance(accounts.bob, 1), Err(Error::Overflow));
+        }
+
+        #[ink::test]
+        fn transaction_history_is_recorded_and_paginated() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut erc20 = Erc20::new(
+                String::from("Ink Test Token"),
+                String::from("ITT"),
+                18,
+                1000,
+                accounts.django,
+                accounts.eve);
+
+            assert_eq!(erc20.transfer(accounts.bob, 100), Ok(()));
+            assert_eq!(erc20.transfer(accounts.bob, 50), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(erc20.mint(accounts.alice, 10), Ok(()));
+
+            let history = erc20.transaction_history(accounts.alice, 0, 2);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].action, TxAction::Mint);
+            assert_eq!(history[0].amount, 10);
+            assert_eq!(history[1].action, TxAction::Transfer);
+            assert_eq!(history[1].amount, 50);
+
+            let next_page = erc20.transaction_history(accounts.alice, 1, 2);
+            assert_eq!(next_page.len(), 1);
+            assert_eq!(next_page[0].amount, 100);
+
+            let bob_history = erc20.transaction_history(accounts.bob, 0, 10);
+            assert_eq!(bob_history.len(), 2);
+            assert_eq!(bob_history[0].counterparty, Some(accounts.alice));
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]